@@ -1,15 +1,134 @@
-use actix_web::{web, App, HttpServer, Responder, HttpResponse, Error};
+use actix_web::{web, App, HttpServer, Responder, HttpResponse};
 use chrono::Utc;
-use jsonwebtoken::{decode, DecodingKey, Validation};
+use hmac::{Hmac, Mac};
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::Sha256;
 use std::fs;
+use std::io::BufReader;
 use std::process::Command;
 use std::sync::Mutex;
 
 // Constants
 const CACHE_DURATION_MS: u64 = 5 * 60 * 1000; // 5 minutes
-const JWT_SECRET_KEY: &str = "SECRET_TOKEN";
+const TLS_CERT_PATH_ENV: &str = "TLS_CERT_PATH";
+const TLS_KEY_PATH_ENV: &str = "TLS_KEY_PATH";
+const BIND_ADDRESS_ENV: &str = "BIND_ADDRESS";
+const BIND_PORT_ENV: &str = "BIND_PORT";
+const DEFAULT_BIND_ADDRESS: &str = "127.0.0.1";
+const DEFAULT_BIND_PORT: u16 = 3000;
+const JWT_PUBLIC_KEY_PATH_ENV: &str = "JWT_PUBLIC_KEY_PATH";
+const JWT_ALGORITHM_ENV: &str = "JWT_ALGORITHM";
+const JWT_ISSUER_ENV: &str = "JWT_ISSUER";
+const JWT_AUDIENCE_ENV: &str = "JWT_AUDIENCE";
+const JWT_LEEWAY_SECONDS: u64 = 30;
+const GITHUB_WEBHOOK_SECRET_ENV: &str = "GITHUB_WEBHOOK_SECRET";
+const GITHUB_WEBHOOK_BRANCH_ENV: &str = "GITHUB_WEBHOOK_BRANCH";
+const DEFAULT_WEBHOOK_BRANCH: &str = "main";
+
+/**
+ * Admin operations that can be granted independently of read access.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Operation {
+    Read,
+    Write,
+}
+
+/**
+ * The access a token's `scope` claim grants. New routes declare one of
+ * these as the scope they require, and `require_scope` checks the token's
+ * claim against it.
+ */
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "scope", content = "operation", rename_all = "snake_case")]
+enum SpecificClaims {
+    Status,
+    Admin(Operation),
+}
+
+impl SpecificClaims {
+    /**
+     * Whether this claim grants access to a route requiring `required`.
+     */
+    fn grants(&self, required: &SpecificClaims) -> bool {
+        self == required
+    }
+}
+
+/**
+ * Claims carried by a verified access token. `exp`/`iat`/`iss`/`aud` must
+ * be present on this type for `jsonwebtoken`'s `Validation` to check them
+ * during `decode`, even though the application only reads `scope`
+ * afterwards — hence the blanket allow rather than leaving them unread.
+ */
+#[derive(Debug, Clone, Deserialize)]
+#[allow(dead_code)]
+struct Claims {
+    sub: String,
+    exp: usize,
+    iat: usize,
+    iss: String,
+    aud: String,
+    #[serde(flatten, default)]
+    scope: Option<SpecificClaims>,
+}
+
+/**
+ * Resolved JWT verification settings, loaded once at startup from env vars.
+ */
+struct JwtConfig {
+    decoding_key: DecodingKey,
+    algorithm: Algorithm,
+    issuer: String,
+    audience: String,
+}
+
+impl JwtConfig {
+    /**
+     * Builds the verification config from env vars, panicking at startup if
+     * the configured public key or algorithm can't be loaded.
+     */
+    fn from_env() -> Self {
+        let key_path = std::env::var(JWT_PUBLIC_KEY_PATH_ENV)
+            .unwrap_or_else(|_| panic!("{} must be set", JWT_PUBLIC_KEY_PATH_ENV));
+        let pem = fs::read(&key_path)
+            .unwrap_or_else(|e| panic!("failed to read {}: {}", key_path, e));
+
+        let algorithm = match std::env::var(JWT_ALGORITHM_ENV)
+            .unwrap_or_else(|_| "RS256".to_string())
+            .as_str()
+        {
+            "RS256" => Algorithm::RS256,
+            "ES256" => Algorithm::ES256,
+            other => panic!("unsupported {}: {}", JWT_ALGORITHM_ENV, other),
+        };
+
+        let decoding_key = match algorithm {
+            Algorithm::RS256 => DecodingKey::from_rsa_pem(&pem)
+                .unwrap_or_else(|e| panic!("invalid RSA public key: {}", e)),
+            Algorithm::ES256 => DecodingKey::from_ec_pem(&pem)
+                .unwrap_or_else(|e| panic!("invalid EC public key: {}", e)),
+            _ => unreachable!("algorithm is restricted to RS256/ES256 above"),
+        };
+
+        JwtConfig {
+            decoding_key,
+            algorithm,
+            issuer: std::env::var(JWT_ISSUER_ENV)
+                .unwrap_or_else(|_| panic!("{} must be set", JWT_ISSUER_ENV)),
+            audience: std::env::var(JWT_AUDIENCE_ENV)
+                .unwrap_or_else(|_| panic!("{} must be set", JWT_AUDIENCE_ENV)),
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref JWT_CONFIG: JwtConfig = JwtConfig::from_env();
+}
 
 /**
  * In-memory configuration cache to store application metadata and git SHA.
@@ -17,6 +136,8 @@ const JWT_SECRET_KEY: &str = "SECRET_TOKEN";
 struct ConfigCache {
     metadata: Option<Value>,
     sha: Option<String>,
+    commit_message: Option<String>,
+    commit_author: Option<String>,
     last_updated: u64,
 }
 
@@ -24,10 +145,157 @@ lazy_static::lazy_static! {
     static ref CONFIG_CACHE: Mutex<ConfigCache> = Mutex::new(ConfigCache {
         metadata: None,
         sha: None,
+        commit_message: None,
+        commit_author: None,
         last_updated: 0,
     });
 }
 
+const CACHE_BACKEND_ENV: &str = "CACHE_BACKEND";
+const REDIS_URL_ENV: &str = "REDIS_URL";
+const REDIS_CACHE_KEY: &str = "microservices-scaffolding:config";
+
+/**
+ * The metadata+SHA blob shared across cache backends.
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedConfig {
+    metadata: Value,
+    sha: String,
+    commit_message: Option<String>,
+    commit_author: Option<String>,
+    /// When this entry was last refreshed, as millis since the epoch.
+    /// Carried inside the blob (rather than a process-local counter) so
+    /// it reflects the shared value under `CACHE_BACKEND=redis` too.
+    last_updated: u64,
+}
+
+/**
+ * A place to read and write the cached configuration blob. `InMemoryCache`
+ * keeps the existing process-local behavior; `RedisCache` lets a fleet of
+ * replicas share one coherent view, selected via `CACHE_BACKEND`.
+ */
+#[async_trait::async_trait]
+trait CacheBackend: Send + Sync {
+    async fn load(&self) -> Option<CachedConfig>;
+    /**
+     * Like `load`, but ignores the TTL and returns whatever was last
+     * written, however old. Used as a last-known-good fallback when a
+     * refresh fails, so a stale entry isn't treated the same as no entry.
+     * `RedisCache` keeps no copy past its key's TTL, so for it this
+     * returns the same as `load`.
+     */
+    async fn load_stale(&self) -> Option<CachedConfig>;
+    async fn store(&self, config: &CachedConfig);
+}
+
+struct InMemoryCache;
+
+#[async_trait::async_trait]
+impl CacheBackend for InMemoryCache {
+    async fn load(&self) -> Option<CachedConfig> {
+        let cache = CONFIG_CACHE.lock().unwrap();
+        let current_timestamp = Utc::now().timestamp_millis() as u64;
+
+        if let Some(metadata) = &cache.metadata {
+            if (current_timestamp - cache.last_updated) < CACHE_DURATION_MS {
+                return Some(CachedConfig {
+                    metadata: metadata.clone(),
+                    sha: cache.sha.clone().unwrap_or_default(),
+                    commit_message: cache.commit_message.clone(),
+                    commit_author: cache.commit_author.clone(),
+                    last_updated: cache.last_updated,
+                });
+            }
+        }
+
+        None
+    }
+
+    async fn load_stale(&self) -> Option<CachedConfig> {
+        let cache = CONFIG_CACHE.lock().unwrap();
+
+        cache.metadata.as_ref().map(|metadata| CachedConfig {
+            metadata: metadata.clone(),
+            sha: cache.sha.clone().unwrap_or_default(),
+            commit_message: cache.commit_message.clone(),
+            commit_author: cache.commit_author.clone(),
+            last_updated: cache.last_updated,
+        })
+    }
+
+    async fn store(&self, config: &CachedConfig) {
+        let mut cache = CONFIG_CACHE.lock().unwrap();
+        cache.metadata = Some(config.metadata.clone());
+        cache.sha = Some(config.sha.clone());
+        cache.commit_message = config.commit_message.clone();
+        cache.commit_author = config.commit_author.clone();
+        cache.last_updated = config.last_updated;
+    }
+}
+
+struct RedisCache {
+    client: redis::Client,
+}
+
+impl RedisCache {
+    fn from_env() -> Self {
+        let url = std::env::var(REDIS_URL_ENV)
+            .unwrap_or_else(|_| panic!("{} must be set", REDIS_URL_ENV));
+        let client =
+            redis::Client::open(url).unwrap_or_else(|e| panic!("invalid {}: {}", REDIS_URL_ENV, e));
+
+        RedisCache { client }
+    }
+}
+
+#[async_trait::async_trait]
+impl CacheBackend for RedisCache {
+    async fn load(&self) -> Option<CachedConfig> {
+        let mut conn = self.client.get_tokio_connection().await.ok()?;
+        let raw: Option<String> = redis::AsyncCommands::get(&mut conn, REDIS_CACHE_KEY)
+            .await
+            .ok()?;
+        raw.and_then(|raw| serde_json::from_str(&raw).ok())
+    }
+
+    async fn load_stale(&self) -> Option<CachedConfig> {
+        // The Redis key's own EX TTL already governs staleness; once it
+        // expires there's no separate stale copy to fall back to.
+        self.load().await
+    }
+
+    async fn store(&self, config: &CachedConfig) {
+        let Ok(mut conn) = self.client.get_tokio_connection().await else {
+            return;
+        };
+        let Ok(raw) = serde_json::to_string(config) else {
+            return;
+        };
+        let ttl_seconds = (CACHE_DURATION_MS / 1000).max(1);
+        let _: Result<(), _> =
+            redis::AsyncCommands::set_ex(&mut conn, REDIS_CACHE_KEY, raw, ttl_seconds).await;
+    }
+}
+
+/**
+ * Selects the cache backend via `CACHE_BACKEND` (`memory` or `redis`),
+ * defaulting to the in-memory backend.
+ */
+fn build_cache_backend() -> Box<dyn CacheBackend> {
+    match std::env::var(CACHE_BACKEND_ENV)
+        .unwrap_or_else(|_| "memory".to_string())
+        .as_str()
+    {
+        "redis" => Box::new(RedisCache::from_env()),
+        _ => Box::new(InMemoryCache),
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref CACHE_BACKEND: Box<dyn CacheBackend> = build_cache_backend();
+}
+
 /**
  * Utility function to retrieve the latest git commit SHA.
  *
@@ -49,6 +317,140 @@ async fn get_git_sha() -> Result<String, std::io::Error> {
     }
 }
 
+const SHA_SOURCE_ENV: &str = "SHA_SOURCE";
+const GITHUB_REPO_ENV: &str = "GITHUB_REPO";
+const GITHUB_BRANCH_ENV: &str = "GITHUB_BRANCH";
+const GITHUB_TOKEN_ENV: &str = "GITHUB_TOKEN";
+const DEFAULT_GITHUB_BRANCH: &str = "main";
+const GITHUB_USER_AGENT: &str = "microservices-scaffolding";
+
+/**
+ * The latest-commit data surfaced in `/status`, however it was resolved.
+ */
+struct CommitInfo {
+    sha: String,
+    message: Option<String>,
+    author: Option<String>,
+}
+
+/**
+ * Where build metadata's commit info comes from. `LocalGitSource` shells
+ * out to `git`; `GithubApiSource` queries the GitHub REST API so the
+ * service works in containers shipped without `.git` or the `git` binary.
+ */
+// `?Send`: `GithubApiSource`'s `awc` requests are `Rc`-backed and hold
+// non-`Send` state across `.await` points, which a plain `async_trait`
+// future can't accommodate. actix workers are single-threaded, so
+// dropping the `Send` bound on the generated future is safe here.
+#[async_trait::async_trait(?Send)]
+trait CommitSource: Send + Sync {
+    async fn latest_commit(&self) -> Result<CommitInfo, String>;
+}
+
+struct LocalGitSource;
+
+#[async_trait::async_trait(?Send)]
+impl CommitSource for LocalGitSource {
+    async fn latest_commit(&self) -> Result<CommitInfo, String> {
+        let sha = get_git_sha()
+            .await
+            .map_err(|_| "Failed to retrieve Git SHA".to_string())?;
+
+        Ok(CommitInfo {
+            sha,
+            message: None,
+            author: None,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubApiCommitResponse {
+    sha: String,
+    commit: GithubApiCommitDetail,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubApiCommitDetail {
+    message: String,
+    author: Option<GithubApiCommitAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubApiCommitAuthor {
+    name: Option<String>,
+}
+
+struct GithubApiSource {
+    repo: String,
+    branch: String,
+    token: String,
+}
+
+impl GithubApiSource {
+    fn from_env() -> Self {
+        GithubApiSource {
+            repo: std::env::var(GITHUB_REPO_ENV)
+                .unwrap_or_else(|_| panic!("{} must be set", GITHUB_REPO_ENV)),
+            branch: std::env::var(GITHUB_BRANCH_ENV)
+                .unwrap_or_else(|_| DEFAULT_GITHUB_BRANCH.to_string()),
+            token: std::env::var(GITHUB_TOKEN_ENV)
+                .unwrap_or_else(|_| panic!("{} must be set", GITHUB_TOKEN_ENV)),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl CommitSource for GithubApiSource {
+    async fn latest_commit(&self) -> Result<CommitInfo, String> {
+        let url = format!(
+            "https://api.github.com/repos/{}/commits/{}",
+            self.repo, self.branch
+        );
+
+        let mut response = awc::Client::default()
+            .get(&url)
+            .insert_header(("User-Agent", GITHUB_USER_AGENT))
+            .insert_header(("Authorization", format!("Bearer {}", self.token)))
+            .send()
+            .await
+            .map_err(|e| format!("GitHub API request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("GitHub API returned {}", response.status()));
+        }
+
+        let body: GithubApiCommitResponse = response
+            .json()
+            .await
+            .map_err(|e| format!("failed to parse GitHub API response: {}", e))?;
+
+        Ok(CommitInfo {
+            sha: body.sha,
+            message: Some(body.commit.message),
+            author: body.commit.author.and_then(|author| author.name),
+        })
+    }
+}
+
+/**
+ * Selects the commit source via `SHA_SOURCE` (`local-git` or `github-api`),
+ * defaulting to `local-git`.
+ */
+fn build_commit_source() -> Box<dyn CommitSource> {
+    match std::env::var(SHA_SOURCE_ENV)
+        .unwrap_or_else(|_| "local-git".to_string())
+        .as_str()
+    {
+        "github-api" => Box::new(GithubApiSource::from_env()),
+        _ => Box::new(LocalGitSource),
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref COMMIT_SOURCE: Box<dyn CommitSource> = build_commit_source();
+}
+
 /**
  * Utility function to handle error responses in the API.
  *
@@ -62,65 +464,272 @@ fn handle_error_response(status_code: u16, message: &str) -> HttpResponse {
         .json(serde_json::json!({"error": message}))
 }
 
+/**
+ * Re-reads `metadata.json` and the Git SHA from source, writes the result
+ * into the cache backend, and records the refresh timestamp. Used by both
+ * the background refresh task and the on-demand cache-miss fallback.
+ */
+async fn refresh_configuration() -> Result<CachedConfig, String> {
+    let metadata_content = fs::read_to_string("./metadata.json")
+        .map_err(|_| "Failed to read metadata file".to_string())?;
+    let metadata: Value = serde_json::from_str(&metadata_content)
+        .map_err(|_| "Failed to parse metadata content".to_string())?;
+
+    let (commit, last_updated) = match COMMIT_SOURCE.latest_commit().await {
+        Ok(commit) => (commit, Utc::now().timestamp_millis() as u64),
+        Err(e) => match CACHE_BACKEND.load_stale().await {
+            Some(cached) => {
+                eprintln!(
+                    "Warning: failed to resolve latest commit ({}), reusing cached SHA",
+                    e
+                );
+                let commit = CommitInfo {
+                    sha: cached.sha,
+                    message: cached.commit_message,
+                    author: cached.commit_author,
+                };
+                // Keep the prior refresh timestamp: we didn't actually
+                // resolve anything new, so don't claim this is fresh.
+                (commit, cached.last_updated)
+            }
+            None => return Err(e),
+        },
+    };
+
+    let config = CachedConfig {
+        metadata,
+        sha: commit.sha,
+        commit_message: commit.message,
+        commit_author: commit.author,
+        last_updated,
+    };
+    CACHE_BACKEND.store(&config).await;
+
+    Ok(config)
+}
+
 /**
  * Asynchronously loads application configuration with intelligent caching.
+ *
+ * Config is normally kept warm by the background refresh task spawned in
+ * `main`; this only falls back to a synchronous refresh on a cache miss
+ * (e.g. before the first background tick). If that refresh fails, the
+ * last-known-good entry from `load_stale` is served instead.
  */
 async fn load_configuration() -> Result<ConfigCache, String> {
-    let current_timestamp = Utc::now().timestamp_millis() as u64;
+    let cached = match CACHE_BACKEND.load().await {
+        Some(cached) => cached,
+        None => match refresh_configuration().await {
+            Ok(cached) => cached,
+            Err(e) => {
+                eprintln!("Warning: config refresh failed, serving last-known-good cache: {}", e);
+                CACHE_BACKEND.load_stale().await.ok_or(e)?
+            }
+        },
+    };
 
-    let mut cache = CONFIG_CACHE.lock().unwrap();
+    Ok(ConfigCache {
+        metadata: Some(cached.metadata),
+        sha: Some(cached.sha),
+        commit_message: cached.commit_message,
+        commit_author: cached.commit_author,
+        last_updated: cached.last_updated,
+    })
+}
 
-    if let Some(metadata) = &cache.metadata {
-        if (current_timestamp - cache.last_updated) < CACHE_DURATION_MS {
-            return Ok(ConfigCache {
-                metadata: Some(metadata.clone()),
-                sha: cache.sha.clone(),
-                last_updated: cache.last_updated,
-            });
+/**
+ * Spawns a background task that proactively refreshes the config cache
+ * every `CACHE_DURATION_MS`, instead of waiting for a lazy expiry to stall
+ * the next `/status` request. On failure it logs and keeps serving the
+ * last-known-good cached value.
+ */
+fn spawn_config_refresh_task() {
+    actix_web::rt::spawn(async {
+        let mut interval =
+            actix_web::rt::time::interval(std::time::Duration::from_millis(CACHE_DURATION_MS));
+
+        loop {
+            interval.tick().await;
+            if let Err(e) = refresh_configuration().await {
+                eprintln!(
+                    "Warning: background config refresh failed, serving last-known-good cache: {}",
+                    e
+                );
+            }
         }
+    });
+}
+
+/**
+ * Middleware to authenticate requests using JSON Web Token (JWT).
+ *
+ * Verifies the signature with the configured asymmetric public key and
+ * enforces expiry, not-before, issuer and audience, returning a distinct
+ * 401 message per failure mode so clients (and logs) can tell them apart.
+ */
+async fn authenticate_token(req: actix_web::HttpRequest) -> Result<Claims, HttpResponse> {
+    let auth_header = req
+        .headers()
+        .get("authorization")
+        .and_then(|h| h.to_str().ok())
+        .ok_or_else(|| handle_error_response(401, "Unauthorized: Missing or invalid token"))?;
+
+    let token = auth_header
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| handle_error_response(401, "Unauthorized: Missing or invalid token"))?;
+
+    let mut validation = Validation::new(JWT_CONFIG.algorithm);
+    validation.leeway = JWT_LEEWAY_SECONDS;
+    validation.validate_nbf = true;
+    validation.set_issuer(&[&JWT_CONFIG.issuer]);
+    validation.set_audience(&[&JWT_CONFIG.audience]);
+
+    match decode::<Claims>(token, &JWT_CONFIG.decoding_key, &validation) {
+        Ok(data) => Ok(data.claims),
+        Err(err) => Err(match err.kind() {
+            ErrorKind::ExpiredSignature => handle_error_response(401, "Unauthorized: token expired"),
+            ErrorKind::InvalidIssuer => handle_error_response(401, "Unauthorized: invalid issuer"),
+            ErrorKind::InvalidAudience => handle_error_response(401, "Unauthorized: invalid audience"),
+            ErrorKind::InvalidSignature | ErrorKind::InvalidAlgorithm => {
+                handle_error_response(401, "Unauthorized: invalid token signature")
+            }
+            ErrorKind::ImmatureSignature => handle_error_response(401, "Unauthorized: token not yet valid"),
+            _ => handle_error_response(401, "Unauthorized: Missing or invalid token"),
+        }),
     }
+}
 
-    // Load metadata
-    let metadata_content = fs::read_to_string("./metadata.json")
-        .map_err(|_| "Failed to read metadata file")?;
-    let metadata: Value =
-        serde_json::from_str(&metadata_content).map_err(|_| "Failed to parse metadata content")?;
+/**
+ * Authenticates the request, then checks that its scope claim grants
+ * `required`, so handlers can opt into fine-grained access control with a
+ * single call: `require_scope(req, SpecificClaims::Status).await?`.
+ *
+ * Returns 401 when the token itself is missing/invalid/expired, and 403
+ * when it's a valid token that simply isn't authorized for `required`.
+ */
+async fn require_scope(
+    req: actix_web::HttpRequest,
+    required: SpecificClaims,
+) -> Result<Claims, HttpResponse> {
+    let claims = authenticate_token(req).await?;
 
-    // Get Git SHA
-    let sha = get_git_sha()
-        .await
-        .map_err(|_| "Failed to retrieve Git SHA".to_string())?;
+    let grants = claims
+        .scope
+        .as_ref()
+        .map(|scope| scope.grants(&required))
+        .unwrap_or(false);
+
+    if grants {
+        Ok(claims)
+    } else {
+        Err(handle_error_response(403, "Forbidden: insufficient scope"))
+    }
+}
 
-    // Update cache
-    cache.metadata = Some(metadata.clone());
-    cache.sha = Some(sha.clone());
-    cache.last_updated = current_timestamp;
+/**
+ * The subset of a GitHub `push` event payload this service cares about.
+ */
+#[derive(Debug, Deserialize)]
+struct GithubPushEvent {
+    #[serde(rename = "ref")]
+    git_ref: String,
+    head_commit: Option<GithubCommit>,
+}
 
-    Ok(ConfigCache {
-        metadata: Some(metadata),
-        sha: Some(sha),
-        last_updated: cache.last_updated,
-    })
+#[derive(Debug, Deserialize)]
+struct GithubCommit {
+    id: String,
+    message: Option<String>,
+    author: Option<GithubCommitAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubCommitAuthor {
+    name: Option<String>,
 }
 
 /**
- * Middleware to authenticate requests using JSON Web Token (JWT).
+ * Verifies `X-Hub-Signature-256: sha256=<hex>` against
+ * `HMAC-SHA256(secret, raw_body)`, comparing in constant time.
  */
-async fn authenticate_token(req: actix_web::HttpRequest) -> Result<(), Error> {
-    if let Some(auth_header) = req.headers().get("authorization") {
-        if let Ok(auth_header) = auth_header.to_str() {
-            let token = auth_header.split_whitespace().nth(1);
+fn verify_webhook_signature(secret: &str, body: &[u8], signature_header: &str) -> bool {
+    let Some(hex_signature) = signature_header.strip_prefix("sha256=") else {
+        return false;
+    };
+    let Ok(expected) = hex::decode(hex_signature) else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
 
-            if let Some(token) = token {
-                let decoding_key = DecodingKey::from_secret(JWT_SECRET_KEY.as_ref());
-                if decode::<Value>(token, &decoding_key, &Validation::default()).is_ok() {
-                    return Ok(());
-                }
-            }
-        }
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/**
+ * Receives GitHub `push` webhook events. On a verified push to the
+ * configured branch, updates the cached SHA directly from the payload so
+ * `/status` reflects the new deploy immediately, without shelling out to
+ * `git`.
+ */
+async fn webhook(req: actix_web::HttpRequest, body: web::Bytes) -> impl Responder {
+    let secret = match std::env::var(GITHUB_WEBHOOK_SECRET_ENV) {
+        Ok(secret) => secret,
+        Err(_) => return handle_error_response(500, "Internal Server Error"),
+    };
+
+    let signature = match req
+        .headers()
+        .get("X-Hub-Signature-256")
+        .and_then(|h| h.to_str().ok())
+    {
+        Some(signature) => signature,
+        None => return handle_error_response(401, "Unauthorized: missing signature"),
+    };
+
+    if !verify_webhook_signature(&secret, &body, signature) {
+        return handle_error_response(401, "Unauthorized: invalid signature");
     }
 
-    Err(handle_error_response(401, "Unauthorized: Missing or invalid token").into())
+    let event: GithubPushEvent = match serde_json::from_slice(&body) {
+        Ok(event) => event,
+        Err(_) => return handle_error_response(400, "Bad Request: invalid payload"),
+    };
+
+    let target_branch = std::env::var(GITHUB_WEBHOOK_BRANCH_ENV)
+        .unwrap_or_else(|_| DEFAULT_WEBHOOK_BRANCH.to_string());
+    let pushed_branch = event
+        .git_ref
+        .strip_prefix("refs/heads/")
+        .unwrap_or(&event.git_ref);
+
+    if pushed_branch != target_branch {
+        return HttpResponse::Ok().json(serde_json::json!({"ignored": true, "branch": pushed_branch}));
+    }
+
+    let Some(commit) = event.head_commit else {
+        return handle_error_response(400, "Bad Request: missing head_commit");
+    };
+
+    let metadata = CACHE_BACKEND
+        .load_stale()
+        .await
+        .map(|cached| cached.metadata)
+        .unwrap_or(Value::Null);
+
+    CACHE_BACKEND
+        .store(&CachedConfig {
+            metadata,
+            sha: commit.id.clone(),
+            commit_message: commit.message.clone(),
+            commit_author: commit.author.and_then(|author| author.name),
+            last_updated: Utc::now().timestamp_millis() as u64,
+        })
+        .await;
+
+    HttpResponse::Ok().json(serde_json::json!({"updated": true, "sha": commit.id}))
 }
 
 /**
@@ -133,7 +742,11 @@ async fn root() -> impl Responder {
 /**
  * Status endpoint providing application metadata and build information.
  */
-async fn status() -> impl Responder {
+async fn status(req: actix_web::HttpRequest) -> impl Responder {
+    if let Err(e) = require_scope(req, SpecificClaims::Status).await {
+        return e;
+    }
+
     match load_configuration().await {
         Ok(config) => {
             if let Some(metadata) = config.metadata {
@@ -144,8 +757,11 @@ async fn status() -> impl Responder {
                             "description": metadata["description"].clone(),
                             "version": format!("{}-{}", metadata["version"], build_number),
                             "sha": config.sha.unwrap_or_default(),
+                            "commitMessage": config.commit_message,
+                            "commitAuthor": config.commit_author,
                         }
-                    ]
+                    ],
+                    "lastRefreshedAt": config.last_updated,
                 }))
             } else {
                 handle_error_response(500, "Internal Server Error")
@@ -155,17 +771,114 @@ async fn status() -> impl Responder {
     }
 }
 
+/**
+ * Loads a certificate chain and private key (PKCS#8) from PEM files.
+ */
+fn load_cert_chain_and_key(
+    cert_path: &str,
+    key_path: &str,
+) -> (Vec<rustls::Certificate>, rustls::PrivateKey) {
+    let cert_file =
+        fs::File::open(cert_path).unwrap_or_else(|e| panic!("failed to open {}: {}", cert_path, e));
+    let cert_chain = rustls_pemfile::certs(&mut BufReader::new(cert_file))
+        .unwrap_or_else(|e| panic!("failed to parse {}: {}", cert_path, e))
+        .into_iter()
+        .map(rustls::Certificate)
+        .collect();
+
+    let key_file =
+        fs::File::open(key_path).unwrap_or_else(|e| panic!("failed to open {}: {}", key_path, e));
+    let mut keys = rustls_pemfile::pkcs8_private_keys(&mut BufReader::new(key_file))
+        .unwrap_or_else(|e| panic!("failed to parse {}: {}", key_path, e));
+    let private_key = rustls::PrivateKey(
+        keys.pop()
+            .unwrap_or_else(|| panic!("no private key found in {}", key_path)),
+    );
+
+    (cert_chain, private_key)
+}
+
+/**
+ * Generates an in-memory self-signed certificate for local/dev use, so the
+ * scaffold can be run over HTTPS without provisioning real PKI.
+ */
+fn generate_self_signed_cert() -> (Vec<rustls::Certificate>, rustls::PrivateKey) {
+    let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()])
+        .expect("failed to generate self-signed certificate");
+    let cert_der = cert
+        .serialize_der()
+        .expect("failed to serialize self-signed certificate");
+    let key_der = cert.serialize_private_key_der();
+
+    (vec![rustls::Certificate(cert_der)], rustls::PrivateKey(key_der))
+}
+
+/**
+ * Builds the rustls server config used for TLS termination. Loads a cert
+ * chain and key from `TLS_CERT_PATH`/`TLS_KEY_PATH` when both are set,
+ * falls back to a self-signed certificate and logs a warning when neither
+ * is set, and panics when only one of them is set.
+ */
+fn build_tls_config() -> rustls::ServerConfig {
+    let cert_path = std::env::var(TLS_CERT_PATH_ENV).ok();
+    let key_path = std::env::var(TLS_KEY_PATH_ENV).ok();
+
+    let (cert_chain, private_key) = match (cert_path, key_path) {
+        (Some(cert_path), Some(key_path)) => load_cert_chain_and_key(&cert_path, &key_path),
+        (None, None) => {
+            eprintln!(
+                "Warning: {}/{} not set, generating an in-memory self-signed certificate for local/dev use",
+                TLS_CERT_PATH_ENV, TLS_KEY_PATH_ENV
+            );
+            generate_self_signed_cert()
+        }
+        (Some(_), None) => panic!("{} is set but {} is not", TLS_CERT_PATH_ENV, TLS_KEY_PATH_ENV),
+        (None, Some(_)) => panic!("{} is set but {} is not", TLS_KEY_PATH_ENV, TLS_CERT_PATH_ENV),
+    };
+
+    rustls::ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(cert_chain, private_key)
+        .expect("invalid TLS certificate/key pair")
+}
+
 /**
  * Start the server.
  */
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
+    let bind_address =
+        std::env::var(BIND_ADDRESS_ENV).unwrap_or_else(|_| DEFAULT_BIND_ADDRESS.to_string());
+    let bind_port: u16 = std::env::var(BIND_PORT_ENV)
+        .ok()
+        .and_then(|port| port.parse().ok())
+        .unwrap_or(DEFAULT_BIND_PORT);
+    let tls_config = build_tls_config();
+
+    // Force lazily-built globals to initialize now, so a misconfigured
+    // JWT_PUBLIC_KEY_PATH/JWT_ISSUER/JWT_AUDIENCE panics at startup instead
+    // of poisoning the lazy_static Once on the first request.
+    lazy_static::initialize(&JWT_CONFIG);
+    // Same reasoning for the cache backend: a missing or invalid REDIS_URL
+    // under CACHE_BACKEND=redis should fail the boot, not permanently 500
+    // the first `/status` call. (An unrecognised CACHE_BACKEND value
+    // selects the in-memory backend.)
+    lazy_static::initialize(&CACHE_BACKEND);
+    // And for the commit source: a missing GITHUB_REPO/GITHUB_TOKEN under
+    // SHA_SOURCE=github-api should fail the boot too. (An unrecognised
+    // SHA_SOURCE value selects local git.)
+    lazy_static::initialize(&COMMIT_SOURCE);
+
+    spawn_config_refresh_task();
+
     HttpServer::new(|| {
         App::new()
             .route("/", web::get().to(root))
             .route("/status", web::get().to(status))
+            .route("/webhook", web::post().to(webhook))
     })
-        .bind(("127.0.0.1", 3000))?
+        .bind_rustls((bind_address.as_str(), bind_port), tls_config)?
         .run()
         .await
 }
\ No newline at end of file